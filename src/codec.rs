@@ -0,0 +1,276 @@
+use serde::{Deserialize, Serialize};
+use winterfell::{
+    math::fields::f128::BaseElement as Felt, Deserializable, DeserializationError, FieldExtension,
+    HashFunction, ProofOptions, Serializable, SliceReader, StarkProof,
+};
+
+use crate::VdfInputs;
+
+// HEX HELPERS
+// ================================================================================================
+
+/// `serde(with = "codec::hex_felt_vec")` helper for (de)serializing a vector of field elements as
+/// a vector of hex strings.
+pub(crate) mod hex_felt_vec {
+    use super::*;
+
+    pub fn serialize<S: serde::Serializer>(
+        values: &[Felt],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let hex_strings: Vec<String> = values.iter().map(|v| hex::encode(v.to_bytes())).collect();
+        hex_strings.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Felt>, D::Error> {
+        let hex_strings = Vec::<String>::deserialize(deserializer)?;
+        hex_strings
+            .into_iter()
+            .map(|hex_str| {
+                let bytes = hex::decode(&hex_str).map_err(serde::de::Error::custom)?;
+                let mut reader = SliceReader::new(&bytes);
+                Felt::read_from(&mut reader).map_err(serde::de::Error::custom)
+            })
+            .collect()
+    }
+}
+
+/// `serde(with = "codec::hex_bytes")` helper for (de)serializing a byte vector as a hex string.
+mod hex_bytes {
+    use super::*;
+
+    pub fn serialize<S: serde::Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(value))
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let hex_str = String::deserialize(deserializer)?;
+        hex::decode(&hex_str).map_err(serde::de::Error::custom)
+    }
+}
+
+// PROOF OPTIONS MIRROR
+// ================================================================================================
+
+/// Serializable mirror of the handful of [`HashFunction`] variants this crate relies on.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub enum HashFunctionDef {
+    Blake3_192,
+    Blake3_256,
+    Sha3_256,
+}
+
+impl From<HashFunction> for HashFunctionDef {
+    fn from(hash_fn: HashFunction) -> Self {
+        match hash_fn {
+            HashFunction::Blake3_192 => Self::Blake3_192,
+            HashFunction::Blake3_256 => Self::Blake3_256,
+            HashFunction::Sha3_256 => Self::Sha3_256,
+        }
+    }
+}
+
+impl From<HashFunctionDef> for HashFunction {
+    fn from(hash_fn: HashFunctionDef) -> Self {
+        match hash_fn {
+            HashFunctionDef::Blake3_192 => Self::Blake3_192,
+            HashFunctionDef::Blake3_256 => Self::Blake3_256,
+            HashFunctionDef::Sha3_256 => Self::Sha3_256,
+        }
+    }
+}
+
+/// Serializable mirror of the [`FieldExtension`] variants this crate relies on.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub enum FieldExtensionDef {
+    None,
+    Quadratic,
+    Cubic,
+}
+
+impl From<FieldExtension> for FieldExtensionDef {
+    fn from(field_extension: FieldExtension) -> Self {
+        match field_extension {
+            FieldExtension::None => Self::None,
+            FieldExtension::Quadratic => Self::Quadratic,
+            FieldExtension::Cubic => Self::Cubic,
+        }
+    }
+}
+
+impl From<FieldExtensionDef> for FieldExtension {
+    fn from(field_extension: FieldExtensionDef) -> Self {
+        match field_extension {
+            FieldExtensionDef::None => Self::None,
+            FieldExtensionDef::Quadratic => Self::Quadratic,
+            FieldExtensionDef::Cubic => Self::Cubic,
+        }
+    }
+}
+
+/// The subset of [`ProofOptions`] needed to reconstruct a `VdfAir` when verifying a decoded
+/// [`ProofBundle`].
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub struct ProofOptionsDef {
+    pub num_queries: usize,
+    pub blowup_factor: usize,
+    pub grinding_factor: u32,
+    pub hash_fn: HashFunctionDef,
+    pub field_extension: FieldExtensionDef,
+    pub fri_folding_factor: usize,
+    pub fri_max_remainder_degree: usize,
+}
+
+impl From<&ProofOptions> for ProofOptionsDef {
+    fn from(options: &ProofOptions) -> Self {
+        Self {
+            num_queries: options.num_queries(),
+            blowup_factor: options.blowup_factor(),
+            grinding_factor: options.grinding_factor(),
+            hash_fn: options.hash_fn().into(),
+            field_extension: options.field_extension().into(),
+            fri_folding_factor: options.fri_folding_factor(),
+            fri_max_remainder_degree: options.fri_max_remainder_degree(),
+        }
+    }
+}
+
+impl From<ProofOptionsDef> for ProofOptions {
+    fn from(options: ProofOptionsDef) -> Self {
+        ProofOptions::new(
+            options.num_queries,
+            options.blowup_factor,
+            options.grinding_factor,
+            options.hash_fn.into(),
+            options.field_extension.into(),
+            options.fri_folding_factor,
+            options.fri_max_remainder_degree,
+        )
+    }
+}
+
+// PROOF BUNDLE
+// ================================================================================================
+
+/// A proof together with the public inputs and `ProofOptions` needed to verify it, suitable for
+/// storing in text logs, posting to HTTP endpoints, or embedding in config files.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProofBundle {
+    #[serde(with = "hex_bytes")]
+    proof_bytes: Vec<u8>,
+    inputs: VdfInputs,
+    options: ProofOptionsDef,
+}
+
+impl ProofBundle {
+    /// Captures a generated proof, its public inputs, and the options it was proven under.
+    pub fn new(proof: &StarkProof, inputs: VdfInputs, options: &ProofOptions) -> Self {
+        Self {
+            proof_bytes: proof.to_bytes(),
+            inputs,
+            options: options.into(),
+        }
+    }
+
+    /// Serializes this bundle to a JSON string.
+    pub fn to_json(&self) -> Result<String, CodecError> {
+        serde_json::to_string(self).map_err(CodecError::Json)
+    }
+
+    /// Parses a bundle previously produced by [`ProofBundle::to_json`].
+    pub fn from_json(json: &str) -> Result<Self, CodecError> {
+        serde_json::from_str(json).map_err(CodecError::Json)
+    }
+
+    /// Serializes this bundle to a single hex string.
+    pub fn to_hex(&self) -> Result<String, CodecError> {
+        Ok(hex::encode(self.to_json()?))
+    }
+
+    /// Parses a bundle previously produced by [`ProofBundle::to_hex`].
+    pub fn from_hex(hex_str: &str) -> Result<Self, CodecError> {
+        let bytes = hex::decode(hex_str).map_err(CodecError::Hex)?;
+        let json = String::from_utf8(bytes).map_err(CodecError::Utf8)?;
+        Self::from_json(&json)
+    }
+
+    /// Reconstructs the `StarkProof` carried by this bundle.
+    pub fn proof(&self) -> Result<StarkProof, CodecError> {
+        StarkProof::from_bytes(&self.proof_bytes).map_err(CodecError::Proof)
+    }
+
+    /// The public inputs the proof was generated against.
+    pub fn inputs(&self) -> VdfInputs {
+        self.inputs.clone()
+    }
+
+    /// The `ProofOptions` needed to reconstruct the `VdfAir` used to verify this proof.
+    pub fn options(&self) -> ProofOptions {
+        self.options.into()
+    }
+}
+
+// ERRORS
+// ================================================================================================
+
+/// Errors that can occur while encoding or decoding a [`ProofBundle`].
+#[derive(Debug)]
+pub enum CodecError {
+    Json(serde_json::Error),
+    Hex(hex::FromHexError),
+    Utf8(std::string::FromUtf8Error),
+    Proof(DeserializationError),
+}
+
+impl std::fmt::Display for CodecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json(err) => write!(f, "failed to (de)serialize proof bundle as JSON: {err}"),
+            Self::Hex(err) => write!(f, "failed to decode proof bundle from hex: {err}"),
+            Self::Utf8(err) => write!(f, "decoded proof bundle hex was not valid utf-8: {err}"),
+            Self::Proof(err) => write!(f, "failed to deserialize proof bytes: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prove_vdf, VdfConfig};
+
+    /// Cheap `ProofOptions` for tests: small query count and no grinding, so proving a short trace
+    /// stays fast.
+    fn test_options() -> ProofOptions {
+        ProofOptions::new(4, 4, 0, HashFunction::Blake3_256, FieldExtension::None, 4, 31)
+    }
+
+    #[test]
+    fn proof_bundle_json_round_trips() {
+        let (proof, inputs) =
+            prove_vdf(Felt::new(5), 8, VdfConfig::default(), &test_options()).unwrap();
+        let bundle = ProofBundle::new(&proof, inputs, &test_options());
+
+        let json = bundle.to_json().unwrap();
+        let decoded = ProofBundle::from_json(&json).unwrap();
+
+        assert_eq!(decoded.proof().unwrap().to_bytes(), proof.to_bytes());
+    }
+
+    #[test]
+    fn proof_bundle_hex_round_trips() {
+        let (proof, inputs) =
+            prove_vdf(Felt::new(5), 8, VdfConfig::default(), &test_options()).unwrap();
+        let bundle = ProofBundle::new(&proof, inputs, &test_options());
+
+        let hex = bundle.to_hex().unwrap();
+        let decoded = ProofBundle::from_hex(&hex).unwrap();
+
+        assert_eq!(decoded.proof().unwrap().to_bytes(), proof.to_bytes());
+    }
+}
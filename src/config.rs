@@ -0,0 +1,81 @@
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::ToPrimitive;
+use winterfell::math::{fields::f128::BaseElement as Felt, StarkField};
+
+use crate::VdfError;
+
+/// Runtime-configurable VDF parameters.
+///
+/// The crate's recurrence is `state_{i+1} = (state_i - 42)^(1/alpha)`; proving runs the forward
+/// direction with `alpha` (in the AIR's transition constraint) while computing the recurrence
+/// itself needs `inv_alpha = alpha^-1 mod (p - 1)`, where `p` is the f128 field modulus.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VdfConfig {
+    alpha: u64,
+    inv_alpha: u128,
+}
+
+impl VdfConfig {
+    /// Builds a config for the given forward exponent, computing its inverse modulo `p - 1`.
+    /// Fails with [`VdfError::NoInverse`] when `gcd(alpha, p - 1) != 1`, since no such inverse
+    /// exists.
+    pub fn new(alpha: u64) -> Result<Self, VdfError> {
+        let modulus_minus_one = Felt::MODULUS - 1;
+        let inv_alpha =
+            mod_inverse(alpha as u128, modulus_minus_one).ok_or(VdfError::NoInverse { alpha })?;
+        Ok(Self { alpha, inv_alpha })
+    }
+
+    /// The forward exponent used by the AIR's transition constraint.
+    pub fn alpha(&self) -> u64 {
+        self.alpha
+    }
+
+    /// The exponent actually applied at each step of the recurrence, `alpha^-1 mod (p - 1)`.
+    pub fn inv_alpha(&self) -> u128 {
+        self.inv_alpha
+    }
+}
+
+impl Default for VdfConfig {
+    /// The exponent this crate originally hardcoded.
+    fn default() -> Self {
+        Self::new(3).expect("alpha = 3 is invertible modulo p - 1")
+    }
+}
+
+/// Computes `a^-1 mod m` via the extended Euclidean algorithm, or `None` if `gcd(a, m) != 1`.
+/// Uses arbitrary-precision arithmetic since `m` (the f128 modulus minus one) exceeds `i128::MAX`.
+fn mod_inverse(a: u128, m: u128) -> Option<u128> {
+    let egcd = BigInt::from(a).extended_gcd(&BigInt::from(m));
+    if egcd.gcd != BigInt::from(1) {
+        return None;
+    }
+    egcd.x.mod_floor(&BigInt::from(m)).to_u128()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_computes_an_inverse_that_round_trips() {
+        let config = VdfConfig::new(3).unwrap();
+        let modulus_minus_one = BigInt::from(Felt::MODULUS - 1);
+        let product = BigInt::from(config.alpha() as u128) * BigInt::from(config.inv_alpha());
+        assert_eq!(product.mod_floor(&modulus_minus_one), BigInt::from(1));
+    }
+
+    #[test]
+    fn new_rejects_an_alpha_with_no_inverse() {
+        // p - 1 is always even since p is an odd prime, so alpha = 2 never has an inverse mod p - 1.
+        let err = VdfConfig::new(2).unwrap_err();
+        assert!(matches!(err, VdfError::NoInverse { alpha: 2 }));
+    }
+
+    #[test]
+    fn default_is_alpha_three() {
+        assert_eq!(VdfConfig::default().alpha(), 3);
+    }
+}
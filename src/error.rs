@@ -0,0 +1,79 @@
+use winterfell::{ProverError, VerifierError};
+
+use crate::codec::CodecError;
+
+/// Errors that can occur while proving or verifying a VDF.
+#[derive(Debug)]
+pub enum VdfError {
+    /// The STARK prover itself failed to produce a proof for the trace.
+    Proving(ProverError),
+    /// The proof could not be serialized to or deserialized from bytes.
+    ProofCodec(CodecError),
+    /// The proof failed verification against the given public inputs.
+    Verification(VerifierError),
+    /// Fragments were not supplied in index order when reassembling a fragmented proof.
+    FragmentOrder { expected: usize, actual: usize },
+    /// The digest recomputed from a fragmented proof's fragments did not match the committed
+    /// digest.
+    FragmentDigestMismatch,
+    /// The requested forward exponent has no inverse modulo `p - 1`, so a `VdfConfig` could not
+    /// be constructed for it.
+    NoInverse { alpha: u64 },
+}
+
+impl std::fmt::Display for VdfError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Proving(err) => write!(f, "failed to generate proof: {err}"),
+            Self::ProofCodec(err) => write!(f, "failed to (de)serialize proof: {err}"),
+            Self::Verification(err) => write!(f, "proof verification failed: {err}"),
+            Self::FragmentOrder { expected, actual } => write!(
+                f,
+                "fragments out of order: expected fragment index {expected}, found {actual}"
+            ),
+            Self::FragmentDigestMismatch => {
+                write!(
+                    f,
+                    "recomputed fragment digest did not match the committed digest"
+                )
+            }
+            Self::NoInverse { alpha } => {
+                write!(
+                    f,
+                    "{alpha} has no inverse modulo p - 1; choose a different alpha"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for VdfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Proving(err) => Some(err),
+            Self::ProofCodec(err) => Some(err),
+            Self::Verification(err) => Some(err),
+            Self::FragmentOrder { .. } => None,
+            Self::FragmentDigestMismatch => None,
+            Self::NoInverse { .. } => None,
+        }
+    }
+}
+
+impl From<ProverError> for VdfError {
+    fn from(err: ProverError) -> Self {
+        Self::Proving(err)
+    }
+}
+
+impl From<CodecError> for VdfError {
+    fn from(err: CodecError) -> Self {
+        Self::ProofCodec(err)
+    }
+}
+
+impl From<VerifierError> for VdfError {
+    fn from(err: VerifierError) -> Self {
+        Self::Verification(err)
+    }
+}
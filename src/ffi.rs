@@ -0,0 +1,162 @@
+use std::slice;
+
+use winterfell::{
+    math::{fields::f128::BaseElement as Felt, FieldElement},
+    Deserializable, FieldExtension, HashFunction, ProofOptions, Serializable, SliceReader,
+    StarkProof,
+};
+
+use crate::{prove_vdf, verify_vdf, VdfConfig, VdfInputs};
+
+/// `vdf_prove` is pinned to this fixed proof configuration, since non-Rust callers have no way to
+/// construct a `ProofOptions` themselves. `vdf_verify` does not use it: it checks a proof under the
+/// options recorded in that proof.
+fn ffi_proof_options() -> ProofOptions {
+    ProofOptions::new(
+        40,
+        4,
+        21,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        8,
+        64,
+    )
+}
+
+/// Status codes returned by the FFI entry points in this module.
+#[repr(i32)]
+pub enum VdfStatus {
+    Ok = 0,
+    InvalidSeed = 1,
+    ProvingFailed = 2,
+}
+
+/// An opaque, caller-owned byte buffer handed back across the FFI boundary. Must be released with
+/// [`vdf_free_buffer`].
+#[repr(C)]
+pub struct Buffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl Buffer {
+    fn empty() -> Self {
+        Self {
+            data: std::ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn from_vec(mut bytes: Vec<u8>) -> Self {
+        bytes.shrink_to_fit();
+        let buffer = Self {
+            data: bytes.as_mut_ptr(),
+            len: bytes.len(),
+        };
+        std::mem::forget(bytes);
+        buffer
+    }
+}
+
+fn felt_from_bytes(bytes: &[u8]) -> Option<Felt> {
+    let mut reader = SliceReader::new(bytes);
+    Felt::read_from(&mut reader).ok()
+}
+
+/// Proves a VDF over `n` steps starting at the field element encoded by `seed_ptr`/`seed_len`.
+///
+/// On success, writes a `[proof_len: u64 LE][proof_bytes][seed_bytes][result_bytes]` buffer into
+/// `out_buffer` and returns [`VdfStatus::Ok`]. On failure `out_buffer` is left empty and a
+/// non-zero status is returned instead of unwinding across the FFI boundary.
+///
+/// # Safety
+/// `seed_ptr` must point to `seed_len` readable bytes, and `out_buffer` must point to a valid,
+/// writable `Buffer` that the caller will later release with [`vdf_free_buffer`].
+#[no_mangle]
+pub unsafe extern "C" fn vdf_prove(
+    seed_ptr: *const u8,
+    seed_len: usize,
+    n: u64,
+    out_buffer: *mut Buffer,
+) -> i32 {
+    let seed_bytes = slice::from_raw_parts(seed_ptr, seed_len);
+    let seed = match felt_from_bytes(seed_bytes) {
+        Some(seed) => seed,
+        None => {
+            *out_buffer = Buffer::empty();
+            return VdfStatus::InvalidSeed as i32;
+        }
+    };
+
+    let outcome = std::panic::catch_unwind(|| {
+        prove_vdf(seed, n as usize, VdfConfig::default(), &ffi_proof_options())
+    });
+    let (proof, inputs) =
+        match outcome {
+            Ok(Ok(result)) => result,
+            _ => {
+                *out_buffer = Buffer::empty();
+                return VdfStatus::ProvingFailed as i32;
+            }
+        };
+
+    let proof_bytes = proof.to_bytes();
+    let mut payload = Vec::with_capacity(8 + proof_bytes.len() + 2 * Felt::ELEMENT_BYTES);
+    payload.extend_from_slice(&(proof_bytes.len() as u64).to_le_bytes());
+    payload.extend_from_slice(&proof_bytes);
+    payload.extend_from_slice(&inputs.seeds[0].to_bytes());
+    payload.extend_from_slice(&inputs.results[0].to_bytes());
+
+    *out_buffer = Buffer::from_vec(payload);
+    VdfStatus::Ok as i32
+}
+
+/// Verifies a VDF proof against a seed/result pair, each encoded the same way as the
+/// corresponding section of [`vdf_prove`]'s output buffer.
+///
+/// # Safety
+/// `proof_ptr` must point to `proof_len` readable bytes; `seed_ptr` and `result_ptr` must each
+/// point to at least `Felt::ELEMENT_BYTES` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn vdf_verify(
+    proof_ptr: *const u8,
+    proof_len: usize,
+    seed_ptr: *const u8,
+    result_ptr: *const u8,
+) -> bool {
+    let proof_bytes = slice::from_raw_parts(proof_ptr, proof_len);
+    let proof = match StarkProof::from_bytes(proof_bytes) {
+        Ok(proof) => proof,
+        Err(_) => return false,
+    };
+
+    let seed_bytes = slice::from_raw_parts(seed_ptr, Felt::ELEMENT_BYTES);
+    let result_bytes = slice::from_raw_parts(result_ptr, Felt::ELEMENT_BYTES);
+    let (seed, result) = match (felt_from_bytes(seed_bytes), felt_from_bytes(result_bytes)) {
+        (Some(seed), Some(result)) => (seed, result),
+        _ => return false,
+    };
+
+    verify_vdf(
+        proof,
+        VdfInputs {
+            alpha: VdfConfig::default().alpha(),
+            seeds: vec![seed],
+            results: vec![result],
+        },
+    )
+    .is_ok()
+}
+
+/// Releases a [`Buffer`] previously returned by [`vdf_prove`]. Safe to call on an empty buffer.
+///
+/// # Safety
+/// `buffer` must be a `Buffer` previously returned by `vdf_prove` that has not already been
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn vdf_free_buffer(buffer: Buffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+    drop(Vec::from_raw_parts(buffer.data, buffer.len, buffer.len));
+}
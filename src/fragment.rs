@@ -0,0 +1,106 @@
+use winterfell::crypto::{hashers::Blake3_256, Digest, Hasher};
+use winterfell::math::fields::f128::BaseElement as Felt;
+
+use crate::VdfError;
+
+type FragmentHasher = Blake3_256<Felt>;
+
+/// The digest type fragments are committed under; this is the crate's configured `HashFunction`
+/// (Blake3_256) applied over raw bytes rather than field elements.
+pub type FragmentDigest = <FragmentHasher as Hasher>::Digest;
+
+/// One chunk of a fragmented proof, tagged with its position so fragments can be reassembled in
+/// order regardless of the order they arrive in.
+#[derive(Clone)]
+pub struct Fragment {
+    pub index: usize,
+    pub data: Vec<u8>,
+}
+
+/// Splits `proof_bytes` into fragments of at most `frag_size` bytes each and folds their digests
+/// into a single rolling commitment: `acc = H(acc || H(fragment))`, seeded with `H(&[])`.
+pub fn fragment_proof(proof_bytes: &[u8], frag_size: usize) -> (Vec<Fragment>, FragmentDigest) {
+    let fragments: Vec<Fragment> = proof_bytes
+        .chunks(frag_size.max(1))
+        .enumerate()
+        .map(|(index, data)| Fragment {
+            index,
+            data: data.to_vec(),
+        })
+        .collect();
+
+    let digest = fold_fragment_digests(&fragments);
+    (fragments, digest)
+}
+
+/// Reassembles a fragmented proof, verifying that the fragments are in index order and that
+/// folding their digests reproduces `expected_digest`, before returning the full proof bytes.
+pub fn verify_fragments(
+    fragments: &[Fragment],
+    expected_digest: FragmentDigest,
+) -> Result<Vec<u8>, VdfError> {
+    for (position, fragment) in fragments.iter().enumerate() {
+        if fragment.index != position {
+            return Err(VdfError::FragmentOrder {
+                expected: position,
+                actual: fragment.index,
+            });
+        }
+    }
+
+    if fold_fragment_digests(fragments) != expected_digest {
+        return Err(VdfError::FragmentDigestMismatch);
+    }
+
+    Ok(fragments.iter().flat_map(|f| f.data.clone()).collect())
+}
+
+fn fold_fragment_digests(fragments: &[Fragment]) -> FragmentDigest {
+    let mut acc = FragmentHasher::hash(&[]);
+    for fragment in fragments {
+        let frag_digest = FragmentHasher::hash(&fragment.data);
+        let mut input = acc.as_bytes().to_vec();
+        input.extend_from_slice(&frag_digest.as_bytes());
+        acc = FragmentHasher::hash(&input);
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fragment_and_reassemble_round_trips() {
+        let proof_bytes: Vec<u8> = (0..=255u8).collect();
+        let (fragments, digest) = fragment_proof(&proof_bytes, 16);
+        let reassembled = verify_fragments(&fragments, digest).unwrap();
+        assert_eq!(reassembled, proof_bytes);
+    }
+
+    #[test]
+    fn verify_fragments_rejects_out_of_order_fragments() {
+        let proof_bytes: Vec<u8> = (0..64u8).collect();
+        let (mut fragments, digest) = fragment_proof(&proof_bytes, 16);
+        fragments.swap(0, 1);
+
+        let err = verify_fragments(&fragments, digest).unwrap_err();
+        assert!(matches!(
+            err,
+            VdfError::FragmentOrder {
+                expected: 0,
+                actual: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn verify_fragments_rejects_a_mismatched_digest() {
+        let proof_bytes: Vec<u8> = (0..64u8).collect();
+        let (fragments, _) = fragment_proof(&proof_bytes, 16);
+        let (_, other_digest) = fragment_proof(&[0u8; 64], 16);
+
+        let err = verify_fragments(&fragments, other_digest).unwrap_err();
+        assert!(matches!(err, VdfError::FragmentDigestMismatch));
+    }
+}
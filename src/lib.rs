@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use winterfell::{
+    math::{fields::f128::BaseElement as Felt, FieldElement},
+    Air, AirContext, Assertion, ByteWriter, EvaluationFrame, Prover, Serializable, StarkProof,
+    Trace, TraceInfo, TraceTable, TransitionConstraintDegree,
+};
+pub use winterfell::{FieldExtension, HashFunction, ProofOptions};
+
+pub mod codec;
+mod config;
+mod error;
+pub mod ffi;
+pub mod fragment;
+
+pub use config::VdfConfig;
+pub use error::VdfError;
+
+// CONSTANTS
+// ================================================================================================
+
+const FORTY_TWO: Felt = Felt::new(42);
+
+// PUBLIC API
+// ================================================================================================
+
+/// Computes a VDF proof for `n` sequential applications of the VDF recurrence starting at `seed`,
+/// under the given `config` (forward exponent) and `options` (STARK protocol parameters).
+///
+/// Returns the generated proof together with the public inputs (`seed` and the final `result`)
+/// needed to verify it. This is a single-lane convenience wrapper around [`prove_vdf_batch`].
+pub fn prove_vdf(
+    seed: Felt,
+    n: usize,
+    config: VdfConfig,
+    options: &ProofOptions,
+) -> Result<(StarkProof, VdfInputs), VdfError> {
+    prove_vdf_batch(&[seed], n, config, options)
+}
+
+/// Proves `seeds.len()` independent VDF chains of `n` steps each in a single STARK proof,
+/// amortizing the proving cost across all lanes. Every chain runs the same recurrence in its own
+/// trace column; proving cost scales far more gently than proving each chain separately, while
+/// verification remains a single pass.
+pub fn prove_vdf_batch(
+    seeds: &[Felt],
+    n: usize,
+    config: VdfConfig,
+    options: &ProofOptions,
+) -> Result<(StarkProof, VdfInputs), VdfError> {
+    let trace = VdfProver::build_trace(seeds, n, &config);
+    let last_step = n - 1;
+    let results: Vec<Felt> = (0..seeds.len()).map(|column| trace.get(column, last_step)).collect();
+
+    let prover = VdfProver::new(config, options.clone());
+    let pub_inputs = VdfInputs {
+        alpha: config.alpha(),
+        seeds: seeds.to_vec(),
+        results,
+    };
+    let proof = prover.prove(trace)?;
+
+    Ok((proof, pub_inputs))
+}
+
+/// Verifies a VDF proof against the given public inputs. Works for both single-lane proofs and
+/// batched proofs produced by [`prove_vdf_batch`].
+pub fn verify_vdf(proof: StarkProof, inputs: VdfInputs) -> Result<(), VdfError> {
+    winterfell::verify::<VdfAir>(proof, inputs)?;
+    Ok(())
+}
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct VdfInputs {
+    pub(crate) alpha: u64,
+    #[serde(with = "codec::hex_felt_vec")]
+    pub(crate) seeds: Vec<Felt>,
+    #[serde(with = "codec::hex_felt_vec")]
+    pub(crate) results: Vec<Felt>,
+}
+
+impl Serializable for VdfInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.alpha);
+        target.write_u64(self.seeds.len() as u64);
+        target.write_u64(self.results.len() as u64);
+        for seed in &self.seeds {
+            target.write(*seed);
+        }
+        for result in &self.results {
+            target.write(*result);
+        }
+    }
+}
+
+// VDF AIR
+// ================================================================================================
+
+struct VdfAir {
+    context: AirContext<Felt>,
+    alpha: u64,
+    seeds: Vec<Felt>,
+    results: Vec<Felt>,
+}
+
+impl Air for VdfAir {
+    type BaseField = Felt;
+    type PublicInputs = VdfInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: VdfInputs, options: ProofOptions) -> Self {
+        let degrees =
+            vec![TransitionConstraintDegree::new(pub_inputs.alpha as usize); trace_info.width()];
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            alpha: pub_inputs.alpha,
+            seeds: pub_inputs.seeds,
+            results: pub_inputs.results,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        for column in 0..result.len() {
+            let current_state = frame.current()[column];
+            let next_state = frame.next()[column];
+            result[column] = current_state - (next_state.exp(self.alpha.into()) + FORTY_TWO.into());
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        let mut assertions = Vec::with_capacity(self.seeds.len() * 2);
+        for (column, (&seed, &result)) in self.seeds.iter().zip(&self.results).enumerate() {
+            assertions.push(Assertion::single(column, 0, seed));
+            assertions.push(Assertion::single(column, last_step, result));
+        }
+        assertions
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+// PROVER
+// ================================================================================================
+
+struct VdfProver {
+    config: VdfConfig,
+    options: ProofOptions,
+}
+
+impl VdfProver {
+    pub fn new(config: VdfConfig, options: ProofOptions) -> Self {
+        Self { config, options }
+    }
+
+    /// Builds a `TraceTable` with one column per seed, each column running its own copy of the
+    /// VDF recurrence under `config`.
+    pub fn build_trace(seeds: &[Felt], n: usize, config: &VdfConfig) -> TraceTable<Felt> {
+        let mut columns: Vec<Vec<Felt>> = seeds.iter().map(|_| Vec::with_capacity(n)).collect();
+
+        for (column, &seed) in columns.iter_mut().zip(seeds) {
+            let mut state = seed;
+            column.push(state);
+            for _ in 0..(n - 1) {
+                state = (state - FORTY_TWO).exp(config.inv_alpha());
+                column.push(state);
+            }
+        }
+
+        TraceTable::init(columns)
+    }
+}
+
+impl Prover for VdfProver {
+    type BaseField = Felt;
+    type Air = VdfAir;
+    type Trace = TraceTable<Felt>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> VdfInputs {
+        let last_step = trace.length() - 1;
+        let seeds = (0..trace.width())
+            .map(|column| trace.get(column, 0))
+            .collect();
+        let results = (0..trace.width())
+            .map(|column| trace.get(column, last_step))
+            .collect();
+        VdfInputs {
+            alpha: self.config.alpha(),
+            seeds,
+            results,
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheap `ProofOptions` for tests: small query count and no grinding, so proving a short trace
+    /// stays fast.
+    fn test_options() -> ProofOptions {
+        ProofOptions::new(4, 4, 0, HashFunction::Blake3_256, FieldExtension::None, 4, 31)
+    }
+
+    #[test]
+    fn prove_vdf_batch_proves_one_lane_per_seed() {
+        let config = VdfConfig::default();
+        let seeds = [Felt::new(5), Felt::new(7), Felt::new(11)];
+        let options = test_options();
+
+        let (proof, inputs) = prove_vdf_batch(&seeds, 8, config, &options).unwrap();
+
+        assert_eq!(inputs.seeds, seeds);
+        assert_eq!(inputs.results.len(), seeds.len());
+
+        let expected_trace = VdfProver::build_trace(&seeds, 8, &config);
+        for (column, &result) in inputs.results.iter().enumerate() {
+            assert_eq!(result, expected_trace.get(column, 7));
+        }
+
+        verify_vdf(proof, inputs).unwrap();
+    }
+
+    #[test]
+    fn prove_vdf_is_a_single_lane_batch() {
+        let config = VdfConfig::default();
+        let options = test_options();
+
+        let (proof, inputs) = prove_vdf(Felt::new(5), 8, config, &options).unwrap();
+
+        assert_eq!(inputs.seeds.len(), 1);
+        assert_eq!(inputs.results.len(), 1);
+        verify_vdf(proof, inputs).unwrap();
+    }
+}
@@ -1,29 +1,16 @@
 use std::time::Instant;
-use winterfell::{
-    math::{fields::f128::BaseElement as Felt, FieldElement},
-    Air, AirContext, Assertion, ByteWriter, EvaluationFrame, FieldExtension, HashFunction,
-    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceInfo, TraceTable,
-    TransitionConstraintDegree,
-};
-
-// CONSTANTS
-// ================================================================================================
 
-const ALPHA: u64 = 3;
-const INV_ALPHA: u128 = 226854911280625642308916371969163307691;
-const FORTY_TWO: Felt = Felt::new(42);
-
-// MAIN FUNCTION
-// ================================================================================================
+use stark_vdf::{
+    codec::ProofBundle,
+    fragment::{fragment_proof, verify_fragments},
+    prove_vdf, prove_vdf_batch, verify_vdf, FieldExtension, HashFunction, ProofOptions, VdfConfig,
+};
+use winterfell::math::fields::f128::BaseElement as Felt;
 
 pub fn main() {
     let n = 1024 * 1024;
     let seed = Felt::new(5);
-
-    // compute result
-    let now = Instant::now();
-    let result = vdf(seed, n);
-    println!("Computed result in {} ms", now.elapsed().as_millis());
+    let config = VdfConfig::default();
 
     // specify parameters for the STARK protocol
     let stark_params = ProofOptions::new(
@@ -36,18 +23,9 @@ pub fn main() {
         64,
     );
 
-    // instantiate the prover
-    let prover = VdfProver::new(stark_params);
-
-    // build execution trace
+    // compute the result, build the trace, and generate the proof
     let now = Instant::now();
-    let trace = VdfProver::build_trace(seed, n);
-    println!("Built execution trace in {} ms", now.elapsed().as_millis());
-    assert_eq!(result, trace.get(0, n - 1));
-
-    // generate the proof
-    let now = Instant::now();
-    let proof = prover.prove(trace).unwrap();
+    let (proof, pub_inputs) = prove_vdf(seed, n, config, &stark_params).unwrap();
     println!("Generated proof in {} ms", now.elapsed().as_millis());
 
     // serialize proof and check security level
@@ -55,138 +33,59 @@ pub fn main() {
     println!("Proof size: {:.1} KB", proof_bytes.len() as f64 / 1024f64);
     println!("Proof security: {} bits", proof.security_level(true));
 
-    // deserialize proof
-    let parsed_proof = StarkProof::from_bytes(&proof_bytes).unwrap();
-    assert_eq!(proof, parsed_proof);
-
-    // initialize public inputs
-    let pub_inputs = VdfInputs { seed, result };
+    // bundle the proof together with its public inputs and the options needed to verify it, so
+    // it can be stored or transmitted as text instead of as an opaque byte blob
+    let bundle = ProofBundle::new(&proof, pub_inputs, &stark_params);
+    let bundle_json = bundle.to_json().unwrap();
+    println!("Proof bundle: {} bytes of JSON", bundle_json.len());
 
     // verify the proof
     let now = Instant::now();
-    match winterfell::verify::<VdfAir>(proof, pub_inputs) {
+    match verify_vdf(proof, bundle.inputs()) {
         Ok(_) => println!(
             "Proof verified in {:.1} ms",
             now.elapsed().as_micros() as f64 / 1000f64
         ),
-        Err(msg) => println!("Something went wrong! {}", msg),
+        Err(err) => println!("Something went wrong! {}", err),
     }
-}
-
-// VDF FUNCTION
-// ================================================================================================
-
-fn vdf(seed: Felt, n: usize) -> Felt {
-    let mut state = seed;
-    for _ in 0..(n - 1) {
-        state = (state - FORTY_TWO).exp(INV_ALPHA);
-    }
-    state
-}
 
-// PUBLIC INPUTS
-// ================================================================================================
-
-#[derive(Clone)]
-struct VdfInputs {
-    seed: Felt,
-    result: Felt,
-}
-
-impl Serializable for VdfInputs {
-    fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write(self.seed);
-        target.write(self.result);
-    }
-}
-
-// VDF AIR
-// ================================================================================================
-
-struct VdfAir {
-    context: AirContext<Felt>,
-    seed: Felt,
-    result: Felt,
-}
-
-impl Air for VdfAir {
-    type BaseField = Felt;
-    type PublicInputs = VdfInputs;
-
-    fn new(trace_info: TraceInfo, pub_inputs: VdfInputs, options: ProofOptions) -> Self {
-        let degrees = vec![TransitionConstraintDegree::new(3)];
-        Self {
-            context: AirContext::new(trace_info, degrees, options),
-            seed: pub_inputs.seed,
-            result: pub_inputs.result,
-        }
-    }
-
-    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
-        &self,
-        frame: &EvaluationFrame<E>,
-        _periodic_values: &[E],
-        result: &mut [E],
-    ) {
-        let current_state = frame.current()[0];
-        let next_state = frame.next()[0];
-
-        result[0] = current_state - (next_state.exp(ALPHA.into()) + FORTY_TWO.into());
-    }
+    // parse the bundle back from JSON and verify it independently
+    let decoded = ProofBundle::from_json(&bundle_json).unwrap();
+    verify_vdf(decoded.proof().unwrap(), decoded.inputs()).unwrap();
 
-    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
-        let last_step = self.trace_length() - 1;
-        vec![
-            Assertion::single(0, 0, self.seed),
-            Assertion::single(0, last_step, self.result),
-        ]
-    }
-
-    fn context(&self) -> &AirContext<Self::BaseField> {
-        &self.context
-    }
-}
-
-// PROVER
-// ================================================================================================
-
-struct VdfProver {
-    options: ProofOptions,
-}
-
-impl VdfProver {
-    pub fn new(options: ProofOptions) -> Self {
-        Self { options }
-    }
-
-    pub fn build_trace(seed: Felt, n: usize) -> TraceTable<Felt> {
-        let mut trace = Vec::with_capacity(n);
-        let mut state = seed;
-
-        trace.push(state);
-        for _ in 0..(n - 1) {
-            state = (state - FORTY_TWO).exp(INV_ALPHA);
-            trace.push(state);
-        }
-
-        TraceTable::init(vec![trace])
-    }
-}
-
-impl Prover for VdfProver {
-    type BaseField = Felt;
-    type Air = VdfAir;
-    type Trace = TraceTable<Felt>;
-
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> VdfInputs {
-        let last_step = trace.length() - 1;
-        VdfInputs {
-            seed: trace.get(0, 0),
-            result: trace.get(0, last_step),
-        }
-    }
-
-    fn options(&self) -> &ProofOptions {
-        &self.options
-    }
+    // prove several independent VDF chains in one proof, amortizing the STARK cost across lanes
+    let lane_seeds = [Felt::new(5), Felt::new(7), Felt::new(11)];
+    let now = Instant::now();
+    let (batch_proof, batch_inputs) =
+        prove_vdf_batch(&lane_seeds, n, config, &stark_params).unwrap();
+    println!(
+        "Generated {}-lane batch proof in {} ms",
+        lane_seeds.len(),
+        now.elapsed().as_millis()
+    );
+    verify_vdf(batch_proof, batch_inputs).unwrap();
+
+    // split the proof into fragments for incremental upload, then reassemble and verify them
+    // against the committed digest before trusting the result
+    let (fragments, digest) = fragment_proof(&proof_bytes, 1024);
+    println!("Split proof into {} fragments", fragments.len());
+    let reassembled = verify_fragments(&fragments, digest).unwrap();
+    assert_eq!(reassembled, proof_bytes);
+
+    // tune the VDF for a different delay/security tradeoff without editing crate constants: a
+    // custom forward exponent and a quadratic field extension for extra security margin
+    let custom_config = VdfConfig::new(5).unwrap();
+    let custom_params = ProofOptions::new(
+        40,
+        4,
+        21,
+        HashFunction::Blake3_256,
+        FieldExtension::Quadratic,
+        8,
+        64,
+    );
+    let (custom_proof, custom_inputs) =
+        prove_vdf(Felt::new(9), 1024, custom_config, &custom_params).unwrap();
+    verify_vdf(custom_proof, custom_inputs).unwrap();
+    println!("Verified a custom alpha=5 proof under a quadratic field extension");
 }